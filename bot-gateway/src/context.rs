@@ -20,6 +20,14 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 pub type ShardInfo = (Option<Session>, Option<String>);
 
+/// Redis key of the frozen resume URL, prefixed by the cache's namespace
+pub fn resume_url_key<C>(cache: &RedisCache<C>) -> String {
+    match cache.namespace() {
+        Some(namespace) => format!("{namespace}:resume_url"),
+        None => String::from("resume_url"),
+    }
+}
+
 type GatewayEvent = Result<Event, ReceiveMessageError>;
 
 #[derive(Serialize)]
@@ -75,7 +83,7 @@ impl Context {
         let mut conn = self.shared.cache.connection().await?;
         if let Some(url) = info.1 {
             println!("Freezing resume URL: {}", url);
-            conn.set::<_, _, ()>("resume_url", url).await?;
+            conn.set::<_, _, ()>(resume_url_key(self.cache()), url).await?;
         }
         if let Some(session) = info.0 {
             println!("Freezing session ID: {}", session.id());
@@ -104,7 +112,7 @@ impl Context {
         }
 
         let mut conn = cache.pool().get().await?;
-        let url: Option<String> = conn.get("resume_url").await?;
+        let url: Option<String> = conn.get(resume_url_key(cache)).await?;
         if let Some(ref url) = url {
             println!("Found resume URL: {}", url);
         }
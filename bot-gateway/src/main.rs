@@ -99,7 +99,10 @@ async fn main() -> anyhow::Result<()> {
 
     let manager = bb8_redis::RedisConnectionManager::new(redis_url)?;
     let pool = bb8_redis::bb8::Pool::builder().build(manager).await?;
-    let cache = RedisCache::<RedisConfig>::new_with_pool(pool).await?;
+    let redis_namespace = env::var("REDIS_NAMESPACE").unwrap_or_default();
+    println!("Redis namespace: {:?}", redis_namespace);
+
+    let cache = RedisCache::<RedisConfig>::new_with_pool_and_namespace(pool, &redis_namespace).await?;
     let cache = Arc::new(cache);
     let mut _conn = cache.pool().get().await?;
     println!("INFO: Redis cache configured");
@@ -152,7 +155,7 @@ async fn main() -> anyhow::Result<()> {
                 let mut conn = cache.connection().await
                     .expect("Failed to get connection");
                 println!("Freezing resume URL: {}", &url);
-                conn.set::<_, _, ()>("resume_url", &url).await
+                conn.set::<_, _, ()>(context::resume_url_key(&cache), &url).await
                     .expect("Failed to set resume URL");
             }
         }